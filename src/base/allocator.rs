@@ -0,0 +1,118 @@
+//! Abstract definition of a matrix data storage allocator.
+
+use std::any::Any;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::collections::TryReserveError as StdTryReserveError;
+#[cfg(feature = "std")]
+use std::collections::TryReserveError as StdTryReserveError;
+
+use crate::base::dimension::{Dim, U1};
+use crate::base::storage::ContiguousStorageMut;
+
+/// Error returned when a fallible allocation (see
+/// [`BaseAllocator::try_allocate_from_iterator`]) could not be satisfied.
+///
+/// This is kept independent of `alloc`/`std` so that buffers that never allocate (e.g.
+/// [`ArrayStorage`](crate::base::array_storage::ArrayStorage)) can implement the fallible
+/// allocation API uniformly, even in `no_std` builds with the `alloc` feature disabled.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TryReserveError {
+    _priv: (),
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl From<StdTryReserveError> for TryReserveError {
+    #[inline]
+    fn from(_: StdTryReserveError) -> Self {
+        TryReserveError { _priv: () }
+    }
+}
+
+/*
+ *
+ * Allocator.
+ *
+ */
+/// A matrix data allocator dedicated to the given owned matrix storage.
+pub trait BaseAllocator<T, R: Dim, C: Dim = U1>: Any + Sized {
+    /// The type of buffer this allocator can instanciate.
+    type Buffer: ContiguousStorageMut<T, R, C> + Clone;
+
+    /// Allocates a buffer initialized with the content of the given iterator.
+    ///
+    /// Not available under the `no-oom-abort` feature: a heap-backed implementation of this
+    /// method has no way to report an allocation failure other than panicking or aborting the
+    /// process, which that feature promises never happens. Use
+    /// [`Self::try_allocate_from_iterator`] instead.
+    #[cfg(not(feature = "no-oom-abort"))]
+    fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: R,
+        ncols: C,
+        iter: I,
+    ) -> Self::Buffer;
+
+    /// Allocates a buffer initialized with the content of the given iterator, reporting an
+    /// error instead of panicking/aborting the process if the backing storage cannot be grown
+    /// to hold it.
+    ///
+    /// For buffers that require no heap allocation (e.g.
+    /// [`ArrayStorage`](crate::base::array_storage::ArrayStorage)), this always succeeds, and is
+    /// available even without the `alloc` feature.
+    fn try_allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: R,
+        ncols: C,
+        iter: I,
+    ) -> Result<Self::Buffer, TryReserveError>;
+}
+
+/// A matrix data allocator dedicated to the given owned matrix storage.
+///
+/// Blanket-implemented for every [`BaseAllocator`]; kept as a separate trait so that bounds
+/// written against `Allocator<T, R, C>` (the common case) don't need to spell out
+/// `BaseAllocator` explicitly.
+pub trait Allocator<T, R: Dim, C: Dim = U1>: BaseAllocator<T, R, C> {}
+
+impl<T, R: Dim, C: Dim, A: BaseAllocator<T, R, C>> Allocator<T, R, C> for A {}
+
+/*
+ *
+ * Reallocator.
+ *
+ */
+/// An allocator that can be used to reallocate a matrix with a different shape, copying over the
+/// elements it already held.
+pub trait Reallocator<T, RFrom: Dim, CFrom: Dim, RTo: Dim, CTo: Dim>:
+    Allocator<T, RFrom, CFrom> + Allocator<T, RTo, CTo>
+{
+    /// Reallocates a buffer of shape `(RTo, CTo)`, possibly reusing `buf`, and copies
+    /// `buf`'s content into it.
+    ///
+    /// # Safety
+    /// The following invariants must be respected by the implementors of this method:
+    /// * The elements of `buf` are not dropped.
+    /// * The unitialized elements of the returned buffer are not dropped.
+    /// * The elements of `buf` are copied into the returned buffer.
+    ///
+    /// Not available under the `no-oom-abort` feature, for the same reason
+    /// [`BaseAllocator::allocate_from_iterator`] is not: it provides no way to report that the
+    /// reallocation failed.
+    #[cfg(not(feature = "no-oom-abort"))]
+    unsafe fn reallocate_copy(
+        rto: RTo,
+        cto: CTo,
+        buf: <Self as BaseAllocator<T, RFrom, CFrom>>::Buffer,
+    ) -> <Self as BaseAllocator<T, RTo, CTo>>::Buffer;
+
+    /// Reallocates a buffer of shape `(RTo, CTo)`, possibly reusing `buf`, and copies
+    /// `buf`'s content into it, reporting an error instead of panicking/aborting if the
+    /// backing storage cannot be grown to hold it.
+    ///
+    /// # Safety
+    /// Same invariants as [`Self::reallocate_copy`].
+    unsafe fn try_reallocate_copy(
+        rto: RTo,
+        cto: CTo,
+        buf: <Self as BaseAllocator<T, RFrom, CFrom>>::Buffer,
+    ) -> Result<<Self as BaseAllocator<T, RTo, CTo>>::Buffer, TryReserveError>;
+}
@@ -0,0 +1,262 @@
+//! An allocator that draws its dynamically-sized buffers from a caller-chosen allocator instead
+//! of always going through the global heap.
+//!
+//! Everything in this module depends on the unstable `allocator_api` (`std::alloc::Allocator`)
+//! and on the `A`-parameterized `VecStorage` it unlocks in `vec_storage.rs`, so the whole module
+//! is gated behind the `allocator_api` feature.
+#![cfg(feature = "allocator_api")]
+
+use std::alloc::Allocator as RawAllocator;
+use std::marker::PhantomData;
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+use crate::base::allocator::{BaseAllocator, Reallocator, TryReserveError};
+use crate::base::dimension::{Dim, DimName, Dynamic};
+use crate::base::vec_storage::VecStorage;
+use crate::base::Matrix;
+
+/// An allocator that behaves like [`DefaultAllocator`](crate::base::default_allocator::DefaultAllocator)
+/// for dynamically-sized buffers, except that the backing `Vec` (and every reallocation it goes
+/// through) is drawn from the allocator `A` instead of the global heap.
+///
+/// `A` must be `Default` so that `AllocatorIn<A>` can stay a zero-sized marker, mirroring how
+/// [`DefaultAllocator`](crate::base::default_allocator::DefaultAllocator) itself has no state:
+/// an `A` that needs shared state (e.g. a handle into a thread-local arena) should make
+/// `A::default()` return a fresh handle into that same underlying storage.
+///
+/// This lets a caller bound and reuse memory for transient linear-algebra work — e.g. a solver
+/// that allocates scratch matrices once per frame from a bump arena — instead of hitting the
+/// global allocator on every iteration.
+pub struct AllocatorIn<A: RawAllocator>(PhantomData<A>);
+
+// Dynamic - Static
+// Dynamic - Dynamic
+impl<T, C: Dim, A: RawAllocator + Clone + Default + 'static> BaseAllocator<T, Dynamic, C>
+    for AllocatorIn<A>
+{
+    type Buffer = VecStorage<T, Dynamic, C, A>;
+
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: Dynamic,
+        ncols: C,
+        iter: I,
+    ) -> Self::Buffer {
+        let mut res = Vec::new_in(A::default());
+        res.extend(iter);
+        assert!(
+            res.len() == nrows.value() * ncols.value(),
+            "Allocation from iterator error: the iterator did not yield the correct number of elements."
+        );
+
+        VecStorage::new(nrows, ncols, res)
+    }
+
+    #[inline]
+    fn try_allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: Dynamic,
+        ncols: C,
+        iter: I,
+    ) -> Result<Self::Buffer, TryReserveError> {
+        let len = nrows.value() * ncols.value();
+
+        let mut res = Vec::new_in(A::default());
+        res.try_reserve_exact(len)?;
+
+        let mut it = iter.into_iter();
+        let mut count = 0;
+
+        for e in it.by_ref().take(len) {
+            // Safety: `res` was reserved above for exactly `len` elements, so pushing up to
+            // `len` of them can never trigger a reallocation (and therefore can never abort).
+            res.push(e);
+            count += 1;
+        }
+
+        assert!(count == len && it.next().is_none(),
+                "Allocation from iterator error: the iterator did not yield the correct number of elements.");
+
+        Ok(VecStorage::new(nrows, ncols, res))
+    }
+}
+
+// Static - Dynamic
+impl<T, R: DimName, A: RawAllocator + Clone + Default + 'static> BaseAllocator<T, R, Dynamic>
+    for AllocatorIn<A>
+{
+    type Buffer = VecStorage<T, R, Dynamic, A>;
+
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: R,
+        ncols: Dynamic,
+        iter: I,
+    ) -> Self::Buffer {
+        let mut res = Vec::new_in(A::default());
+        res.extend(iter);
+        assert!(
+            res.len() == nrows.value() * ncols.value(),
+            "Allocation from iterator error: the iterator did not yield the correct number of elements."
+        );
+
+        VecStorage::new(nrows, ncols, res)
+    }
+
+    #[inline]
+    fn try_allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: R,
+        ncols: Dynamic,
+        iter: I,
+    ) -> Result<Self::Buffer, TryReserveError> {
+        let len = nrows.value() * ncols.value();
+
+        let mut res = Vec::new_in(A::default());
+        res.try_reserve_exact(len)?;
+
+        let mut it = iter.into_iter();
+        let mut count = 0;
+
+        for e in it.by_ref().take(len) {
+            // Safety: `res` was reserved above for exactly `len` elements, so pushing up to
+            // `len` of them can never trigger a reallocation (and therefore can never abort).
+            res.push(e);
+            count += 1;
+        }
+
+        assert!(count == len && it.next().is_none(),
+                "Allocation from iterator error: the iterator did not yield the correct number of elements.");
+
+        Ok(VecStorage::new(nrows, ncols, res))
+    }
+}
+
+// All conversions between a dynamic-row and a dynamic-column buffer, kept inside `A`.
+impl<T, CFrom: Dim, CTo: Dim, A: RawAllocator + Clone + Default + 'static>
+    Reallocator<T, Dynamic, CFrom, Dynamic, CTo> for AllocatorIn<A>
+{
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    unsafe fn reallocate_copy(
+        rto: Dynamic,
+        cto: CTo,
+        buf: VecStorage<T, Dynamic, CFrom, A>,
+    ) -> VecStorage<T, Dynamic, CTo, A> {
+        let new_buf = buf.resize(rto.value() * cto.value());
+        VecStorage::new(rto, cto, new_buf)
+    }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: Dynamic,
+        cto: CTo,
+        buf: VecStorage<T, Dynamic, CFrom, A>,
+    ) -> Result<VecStorage<T, Dynamic, CTo, A>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
+}
+
+impl<T, CFrom: Dim, RTo: DimName, A: RawAllocator + Clone + Default + 'static>
+    Reallocator<T, Dynamic, CFrom, RTo, Dynamic> for AllocatorIn<A>
+{
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    unsafe fn reallocate_copy(
+        rto: RTo,
+        cto: Dynamic,
+        buf: VecStorage<T, Dynamic, CFrom, A>,
+    ) -> VecStorage<T, RTo, Dynamic, A> {
+        let new_buf = buf.resize(rto.value() * cto.value());
+        VecStorage::new(rto, cto, new_buf)
+    }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: RTo,
+        cto: Dynamic,
+        buf: VecStorage<T, Dynamic, CFrom, A>,
+    ) -> Result<VecStorage<T, RTo, Dynamic, A>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
+}
+
+impl<T, RFrom: DimName, CTo: Dim, A: RawAllocator + Clone + Default + 'static>
+    Reallocator<T, RFrom, Dynamic, Dynamic, CTo> for AllocatorIn<A>
+{
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    unsafe fn reallocate_copy(
+        rto: Dynamic,
+        cto: CTo,
+        buf: VecStorage<T, RFrom, Dynamic, A>,
+    ) -> VecStorage<T, Dynamic, CTo, A> {
+        let new_buf = buf.resize(rto.value() * cto.value());
+        VecStorage::new(rto, cto, new_buf)
+    }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: Dynamic,
+        cto: CTo,
+        buf: VecStorage<T, RFrom, Dynamic, A>,
+    ) -> Result<VecStorage<T, Dynamic, CTo, A>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
+}
+
+impl<T, RFrom: DimName, RTo: DimName, A: RawAllocator + Clone + Default + 'static>
+    Reallocator<T, RFrom, Dynamic, RTo, Dynamic> for AllocatorIn<A>
+{
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    unsafe fn reallocate_copy(
+        rto: RTo,
+        cto: Dynamic,
+        buf: VecStorage<T, RFrom, Dynamic, A>,
+    ) -> VecStorage<T, RTo, Dynamic, A> {
+        let new_buf = buf.resize(rto.value() * cto.value());
+        VecStorage::new(rto, cto, new_buf)
+    }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: RTo,
+        cto: Dynamic,
+        buf: VecStorage<T, RFrom, Dynamic, A>,
+    ) -> Result<VecStorage<T, RTo, Dynamic, A>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
+}
+
+impl<A: RawAllocator + Clone + Default + 'static> AllocatorIn<A> {
+    /// Creates a matrix with the given dimensions from the content of `iter`, drawing its
+    /// backing buffer from `A` instead of the global heap, and reporting an error instead of
+    /// panicking/aborting if it cannot be allocated.
+    ///
+    /// This is the `AllocatorIn`-parameterized counterpart to
+    /// [`OMatrix::try_from_iterator_generic`](crate::base::construction_fallible), giving callers
+    /// an ergonomic way to reach [`BaseAllocator::try_allocate_from_iterator`] without
+    /// hand-assembling a `Matrix` themselves.
+    #[inline]
+    pub fn try_from_iterator_generic<T, R, C, I>(
+        nrows: R,
+        ncols: C,
+        iter: I,
+    ) -> Result<Matrix<T, R, C, <Self as BaseAllocator<T, R, C>>::Buffer>, TryReserveError>
+    where
+        R: Dim,
+        C: Dim,
+        I: IntoIterator<Item = T>,
+        Self: BaseAllocator<T, R, C>,
+    {
+        let data = <Self as BaseAllocator<T, R, C>>::try_allocate_from_iterator(nrows, ncols, iter)?;
+        Ok(Matrix::from_data(data))
+    }
+}
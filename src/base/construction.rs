@@ -0,0 +1,68 @@
+//! Infallible constructors for matrices whose backing buffer allocation is assumed to always
+//! succeed.
+//!
+//! Mirrored by the `Result`-returning equivalents in `construction_fallible.rs`. Every
+//! constructor here is gated out under the `no-oom-abort` feature, since each one bottoms out in
+//! [`BaseAllocator::allocate_from_iterator`](crate::base::allocator::BaseAllocator::allocate_from_iterator),
+//! which has no way to report an allocation failure other than panicking or aborting the
+//! process.
+
+use num_traits::Zero;
+
+use crate::base::allocator::Allocator;
+use crate::base::default_allocator::DefaultAllocator;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::dimension::Dynamic;
+use crate::base::dimension::Dim;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
+use crate::base::{OMatrix, Scalar};
+
+#[cfg(not(feature = "no-oom-abort"))]
+impl<T: Scalar, R: Dim, C: Dim> OMatrix<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Creates a matrix with the given dimensions from the content of `iter`.
+    #[inline]
+    pub fn from_iterator_generic<I>(nrows: R, ncols: C, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_data(DefaultAllocator::allocate_from_iterator(nrows, ncols, iter))
+    }
+}
+
+#[cfg(not(feature = "no-oom-abort"))]
+impl<T: Scalar + Zero, R: Dim, C: Dim> OMatrix<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Creates a matrix filled with zeros.
+    #[inline]
+    pub fn zeros_generic(nrows: R, ncols: C) -> Self {
+        let len = nrows.value() * ncols.value();
+        Self::from_iterator_generic(nrows, ncols, core::iter::repeat_with(T::zero).take(len))
+    }
+}
+
+#[cfg(all(any(feature = "std", feature = "alloc"), not(feature = "no-oom-abort")))]
+impl<T: Scalar> DMatrix<T> {
+    /// Creates a dynamically-sized matrix from the content of `iter`.
+    #[inline]
+    pub fn from_iterator<I>(nrows: usize, ncols: usize, iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::from_iterator_generic(Dynamic::new(nrows), Dynamic::new(ncols), iter)
+    }
+}
+
+#[cfg(all(any(feature = "std", feature = "alloc"), not(feature = "no-oom-abort")))]
+impl<T: Scalar + Zero> DMatrix<T> {
+    /// Creates a dynamically-sized matrix filled with zeros.
+    #[inline]
+    pub fn zeros(nrows: usize, ncols: usize) -> Self {
+        Self::zeros_generic(Dynamic::new(nrows), Dynamic::new(ncols))
+    }
+}
@@ -0,0 +1,82 @@
+//! Fallible constructors for matrices whose backing buffer might not be allocatable.
+//!
+//! These mirror the infallible constructors in `construction.rs`, but go through
+//! [`BaseAllocator::try_allocate_from_iterator`](crate::base::allocator::BaseAllocator::try_allocate_from_iterator)
+//! so that an allocation failure is reported as a `TryReserveError` instead of panicking or
+//! aborting the process.
+//!
+//! Under the `no-oom-abort` feature, `construction.rs`'s infallible constructors are `cfg`-gated
+//! out entirely, leaving this module's `try_*` equivalents as the only way to build a matrix.
+
+use core::iter;
+
+use num_traits::Zero;
+
+use crate::base::allocator::{Allocator, TryReserveError};
+use crate::base::default_allocator::DefaultAllocator;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::dimension::Dynamic;
+use crate::base::dimension::Dim;
+#[cfg(any(feature = "std", feature = "alloc"))]
+use crate::base::DMatrix;
+use crate::base::{OMatrix, Scalar};
+
+impl<T: Scalar, R: Dim, C: Dim> OMatrix<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Creates a matrix with the given dimensions from the content of `iter`, reporting an
+    /// error instead of panicking/aborting if the backing buffer cannot be allocated.
+    #[inline]
+    pub fn try_from_iterator_generic<I>(
+        nrows: R,
+        ncols: C,
+        iter: I,
+    ) -> Result<Self, TryReserveError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let data = DefaultAllocator::try_allocate_from_iterator(nrows, ncols, iter)?;
+        Ok(Self::from_data(data))
+    }
+}
+
+impl<T: Scalar + Zero, R: Dim, C: Dim> OMatrix<T, R, C>
+where
+    DefaultAllocator: Allocator<T, R, C>,
+{
+    /// Creates a matrix filled with zeros, reporting an error instead of panicking/aborting if
+    /// the backing buffer cannot be allocated.
+    #[inline]
+    pub fn try_zeros_generic(nrows: R, ncols: C) -> Result<Self, TryReserveError> {
+        let len = nrows.value() * ncols.value();
+        Self::try_from_iterator_generic(nrows, ncols, iter::repeat_with(T::zero).take(len))
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar> DMatrix<T> {
+    /// Creates a dynamically-sized matrix from the content of `iter`, reporting an error
+    /// instead of panicking/aborting if the backing buffer cannot be allocated.
+    #[inline]
+    pub fn try_from_iterator<I>(
+        nrows: usize,
+        ncols: usize,
+        iter: I,
+    ) -> Result<Self, TryReserveError>
+    where
+        I: IntoIterator<Item = T>,
+    {
+        Self::try_from_iterator_generic(Dynamic::new(nrows), Dynamic::new(ncols), iter)
+    }
+}
+
+#[cfg(any(feature = "std", feature = "alloc"))]
+impl<T: Scalar + Zero> DMatrix<T> {
+    /// Creates a dynamically-sized matrix filled with zeros, reporting an error instead of
+    /// panicking/aborting if the backing buffer cannot be allocated.
+    #[inline]
+    pub fn try_zeros(nrows: usize, ncols: usize) -> Result<Self, TryReserveError> {
+        Self::try_zeros_generic(Dynamic::new(nrows), Dynamic::new(ncols))
+    }
+}
@@ -12,7 +12,7 @@ use std::ptr;
 use alloc::vec::Vec;
 
 use super::Const;
-use crate::base::allocator::{Allocator, BaseAllocator, Reallocator};
+use crate::base::allocator::{Allocator, BaseAllocator, Reallocator, TryReserveError};
 use crate::base::array_storage::ArrayStorage;
 #[cfg(any(feature = "alloc", feature = "std"))]
 use crate::base::dimension::Dynamic;
@@ -32,15 +32,15 @@ use crate::storage::Uninit;
 pub struct DefaultAllocator;
 
 // Static - Static
-impl<T, const R: usize, const C: usize> BaseAllocator<T, Const<R>, Const<C>> for DefaultAllocator {
-    type Buffer = ArrayStorage<T, R, C>;
-
+impl<T, const R: usize, const C: usize> DefaultAllocator {
+    // No heap allocation is involved for a statically-sized buffer, so this helper (shared by
+    // `allocate_from_iterator` and `try_allocate_from_iterator`) can never fail.
     #[inline]
-    fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+    fn allocate_array_from_iterator<I: IntoIterator<Item = T>>(
         nrows: Const<R>,
         ncols: Const<C>,
         iter: I,
-    ) -> Self::Buffer {
+    ) -> ArrayStorage<T, R, C> {
         let mut res = ArrayStorage([[mem::MaybeUninit::uninit(); R]; C]);
         let mut count = 0;
 
@@ -59,6 +59,29 @@ impl<T, const R: usize, const C: usize> BaseAllocator<T, Const<R>, Const<C>> for
     }
 }
 
+impl<T, const R: usize, const C: usize> BaseAllocator<T, Const<R>, Const<C>> for DefaultAllocator {
+    type Buffer = ArrayStorage<T, R, C>;
+
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    fn allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: Const<R>,
+        ncols: Const<C>,
+        iter: I,
+    ) -> Self::Buffer {
+        Self::allocate_array_from_iterator(nrows, ncols, iter)
+    }
+
+    #[inline]
+    fn try_allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: Const<R>,
+        ncols: Const<C>,
+        iter: I,
+    ) -> Result<Self::Buffer, TryReserveError> {
+        Ok(Self::allocate_array_from_iterator(nrows, ncols, iter))
+    }
+}
+
 // Dynamic - Static
 // Dynamic - Dynamic
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -66,6 +89,7 @@ impl<T, C: Dim> BaseAllocator<T, Dynamic, C> for DefaultAllocator {
     type Buffer = VecStorage<T, Dynamic, C>;
 
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     fn allocate_from_iterator<I: IntoIterator<Item = T>>(
         nrows: Dynamic,
         ncols: C,
@@ -78,6 +102,33 @@ impl<T, C: Dim> BaseAllocator<T, Dynamic, C> for DefaultAllocator {
 
         VecStorage::new(nrows, ncols, res)
     }
+
+    #[inline]
+    fn try_allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: Dynamic,
+        ncols: C,
+        iter: I,
+    ) -> Result<Self::Buffer, TryReserveError> {
+        let len = nrows.value() * ncols.value();
+
+        let mut res = Vec::new();
+        res.try_reserve_exact(len)?;
+
+        let mut it = iter.into_iter();
+        let mut count = 0;
+
+        for e in it.by_ref().take(len) {
+            // Safety: `res` was reserved above for exactly `len` elements, so pushing up to
+            // `len` of them can never trigger a reallocation (and therefore can never abort).
+            res.push(e);
+            count += 1;
+        }
+
+        assert!(count == len && it.next().is_none(),
+                "Allocation from iterator error: the iterator did not yield the correct number of elements.");
+
+        Ok(VecStorage::new(nrows, ncols, res))
+    }
 }
 
 // Static - Dynamic
@@ -86,6 +137,7 @@ impl<T, R: DimName> BaseAllocator<T, R, Dynamic> for DefaultAllocator {
     type Buffer = VecStorage<T, R, Dynamic>;
 
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     fn allocate_from_iterator<I: IntoIterator<Item = T>>(
         nrows: R,
         ncols: Dynamic,
@@ -98,6 +150,33 @@ impl<T, R: DimName> BaseAllocator<T, R, Dynamic> for DefaultAllocator {
 
         VecStorage::new(nrows, ncols, res)
     }
+
+    #[inline]
+    fn try_allocate_from_iterator<I: IntoIterator<Item = T>>(
+        nrows: R,
+        ncols: Dynamic,
+        iter: I,
+    ) -> Result<Self::Buffer, TryReserveError> {
+        let len = nrows.value() * ncols.value();
+
+        let mut res = Vec::new();
+        res.try_reserve_exact(len)?;
+
+        let mut it = iter.into_iter();
+        let mut count = 0;
+
+        for e in it.by_ref().take(len) {
+            // Safety: `res` was reserved above for exactly `len` elements, so pushing up to
+            // `len` of them can never trigger a reallocation (and therefore can never abort).
+            res.push(e);
+            count += 1;
+        }
+
+        assert!(count == len && it.next().is_none(),
+                "Allocation from iterator error: the iterator did not yield the correct number of elements.");
+
+        Ok(VecStorage::new(nrows, ncols, res))
+    }
 }
 
 /*
@@ -114,6 +193,7 @@ where
     Self: Allocator<T, RFrom, CFrom>,
 {
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     unsafe fn reallocate_copy(
         rto: Const<RTO>,
         cto: Const<CTO>,
@@ -137,6 +217,32 @@ where
 
         res.assume_init()
     }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: Const<RTO>,
+        cto: Const<CTO>,
+        buf: <Self as BaseAllocator<T, RFrom, CFrom>>::Buffer,
+    ) -> Result<ArrayStorage<T, RTO, CTO>, TryReserveError> {
+        // `ArrayStorage` never allocates, so this can never actually fail.
+        let mut res = <Self as BaseAllocator<mem::MaybeUninit<T>, Const<RTO>, Const<CTO>>>::try_allocate_from_iterator(
+            rto,
+            cto,
+            iter::repeat_with(mem::MaybeUninit::uninit),
+        )?;
+
+        let (rfrom, cfrom) = buf.shape();
+
+        let len_from = rfrom.value() * cfrom.value();
+        let len_to = rto.value() * cto.value();
+        ptr::copy_nonoverlapping(
+            buf.ptr(),
+            res.ptr_mut() as *mut T,
+            cmp::min(len_from, len_to),
+        );
+
+        Ok(res.assume_init())
+    }
 }
 
 // Static × Static -> Dynamic × Any
@@ -147,6 +253,7 @@ where
     CTo: Dim,
 {
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     unsafe fn reallocate_copy(
         rto: Dynamic,
         cto: CTo,
@@ -170,6 +277,31 @@ where
 
         res.assume_init()
     }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: Dynamic,
+        cto: CTo,
+        buf: ArrayStorage<T, RFROM, CFROM>,
+    ) -> Result<VecStorage<T, Dynamic, CTo>, TryReserveError> {
+        let mut res = <Self as BaseAllocator<mem::MaybeUninit<T>, _, _>>::try_allocate_from_iterator(
+            rto,
+            cto,
+            iter::repeat_with(mem::MaybeUninit::uninit).take(rto.value() * cto.value()),
+        )?;
+
+        let (rfrom, cfrom) = buf.shape();
+
+        let len_from = rfrom.value() * cfrom.value();
+        let len_to = rto.value() * cto.value();
+        ptr::copy_nonoverlapping(
+            buf.ptr(),
+            res.ptr_mut() as *mut T,
+            cmp::min(len_from, len_to),
+        );
+
+        Ok(res.assume_init())
+    }
 }
 
 // Static × Static -> Static × Dynamic
@@ -180,6 +312,7 @@ where
     RTo: DimName,
 {
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     unsafe fn reallocate_copy(
         rto: RTo,
         cto: Dynamic,
@@ -203,12 +336,38 @@ where
 
         res.assume_init()
     }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: RTo,
+        cto: Dynamic,
+        buf: ArrayStorage<T, RFROM, CFROM>,
+    ) -> Result<VecStorage<T, RTo, Dynamic>, TryReserveError> {
+        let mut res = <Self as BaseAllocator<mem::MaybeUninit<T>, _, _>>::try_allocate_from_iterator(
+            rto,
+            cto,
+            iter::repeat_with(mem::MaybeUninit::uninit).take(rto.value() * cto.value()),
+        )?;
+
+        let (rfrom, cfrom) = buf.shape();
+
+        let len_from = rfrom.value() * cfrom.value();
+        let len_to = rto.value() * cto.value();
+        ptr::copy_nonoverlapping(
+            buf.ptr(),
+            res.ptr_mut() as *mut T,
+            cmp::min(len_from, len_to),
+        );
+
+        Ok(res.assume_init())
+    }
 }
 
 // All conversion from a dynamic buffer to a dynamic buffer.
 #[cfg(any(feature = "std", feature = "alloc"))]
 impl<T, CFrom: Dim, CTo: Dim> Reallocator<T, Dynamic, CFrom, Dynamic, CTo> for DefaultAllocator {
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     unsafe fn reallocate_copy(
         rto: Dynamic,
         cto: CTo,
@@ -217,6 +376,16 @@ impl<T, CFrom: Dim, CTo: Dim> Reallocator<T, Dynamic, CFrom, Dynamic, CTo> for D
         let new_buf = buf.resize(rto.value() * cto.value());
         VecStorage::new(rto, cto, new_buf)
     }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: Dynamic,
+        cto: CTo,
+        buf: VecStorage<T, Dynamic, CFrom>,
+    ) -> Result<VecStorage<T, Dynamic, CTo>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
 }
 
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -224,6 +393,7 @@ impl<T, CFrom: Dim, RTo: DimName> Reallocator<T, Dynamic, CFrom, RTo, Dynamic>
     for DefaultAllocator
 {
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     unsafe fn reallocate_copy(
         rto: RTo,
         cto: Dynamic,
@@ -232,6 +402,16 @@ impl<T, CFrom: Dim, RTo: DimName> Reallocator<T, Dynamic, CFrom, RTo, Dynamic>
         let new_buf = buf.resize(rto.value() * cto.value());
         VecStorage::new(rto, cto, new_buf)
     }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: RTo,
+        cto: Dynamic,
+        buf: VecStorage<T, Dynamic, CFrom>,
+    ) -> Result<VecStorage<T, RTo, Dynamic>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
 }
 
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -239,6 +419,7 @@ impl<T, RFrom: DimName, CTo: Dim> Reallocator<T, RFrom, Dynamic, Dynamic, CTo>
     for DefaultAllocator
 {
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     unsafe fn reallocate_copy(
         rto: Dynamic,
         cto: CTo,
@@ -247,6 +428,16 @@ impl<T, RFrom: DimName, CTo: Dim> Reallocator<T, RFrom, Dynamic, Dynamic, CTo>
         let new_buf = buf.resize(rto.value() * cto.value());
         VecStorage::new(rto, cto, new_buf)
     }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: Dynamic,
+        cto: CTo,
+        buf: VecStorage<T, RFrom, Dynamic>,
+    ) -> Result<VecStorage<T, Dynamic, CTo>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
 }
 
 #[cfg(any(feature = "std", feature = "alloc"))]
@@ -254,6 +445,7 @@ impl<T, RFrom: DimName, RTo: DimName> Reallocator<T, RFrom, Dynamic, RTo, Dynami
     for DefaultAllocator
 {
     #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
     unsafe fn reallocate_copy(
         rto: RTo,
         cto: Dynamic,
@@ -262,4 +454,14 @@ impl<T, RFrom: DimName, RTo: DimName> Reallocator<T, RFrom, Dynamic, RTo, Dynami
         let new_buf = buf.resize(rto.value() * cto.value());
         VecStorage::new(rto, cto, new_buf)
     }
+
+    #[inline]
+    unsafe fn try_reallocate_copy(
+        rto: RTo,
+        cto: Dynamic,
+        buf: VecStorage<T, RFrom, Dynamic>,
+    ) -> Result<VecStorage<T, RTo, Dynamic>, TryReserveError> {
+        let new_buf = buf.try_resize(rto.value() * cto.value())?;
+        Ok(VecStorage::new(rto, cto, new_buf))
+    }
 }
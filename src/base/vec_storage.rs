@@ -0,0 +1,182 @@
+//! Abstract definition of a matrix data storage for matrices with at least one dimension
+//! unknown at compile-time.
+
+#[cfg(all(feature = "alloc", not(feature = "std")))]
+use alloc::vec::Vec;
+
+#[cfg(feature = "allocator_api")]
+use std::alloc::{Allocator as RawAllocator, Global};
+
+use crate::base::allocator::TryReserveError;
+use crate::base::dimension::Dim;
+
+/// A Vec-based matrix data storage. It may be used for dynamically-sized matrices, or with
+/// combinations of Dynamic and static rows/columns.
+#[cfg(not(feature = "allocator_api"))]
+#[repr(C)]
+#[derive(Eq, Debug, Clone, PartialEq)]
+pub struct VecStorage<T, R: Dim, C: Dim> {
+    data: Vec<T>,
+    nrows: R,
+    ncols: C,
+}
+
+/// A Vec-based matrix data storage. It may be used for dynamically-sized matrices, or with
+/// combinations of Dynamic and static rows/columns.
+///
+/// The backing buffer is drawn from the allocator `A` (the global heap, `Global`, by default).
+/// This lets callers construct matrices whose scratch storage lives in a caller-chosen
+/// bump/arena/pool allocator instead of hitting the global heap, and reuse that memory across
+/// `resize`/`reallocate_copy` calls instead of going back to `Global` every time.
+#[cfg(feature = "allocator_api")]
+#[repr(C)]
+#[derive(Debug, Clone)]
+pub struct VecStorage<T, R: Dim, C: Dim, A: RawAllocator = Global> {
+    data: Vec<T, A>,
+    nrows: R,
+    ncols: C,
+}
+
+// Manual `PartialEq`/`Eq` impls: a derive would add a spurious `A: PartialEq`/`A: Eq` bound,
+// which the default allocator `Global` does not satisfy (mirroring how `Vec<T, A1>` and
+// `Vec<T, A2>` compare without bounding the allocators).
+#[cfg(feature = "allocator_api")]
+impl<T: PartialEq, R: Dim, C: Dim, A: RawAllocator, A2: RawAllocator>
+    PartialEq<VecStorage<T, R, C, A2>> for VecStorage<T, R, C, A>
+{
+    #[inline]
+    fn eq(&self, other: &VecStorage<T, R, C, A2>) -> bool {
+        self.nrows == other.nrows && self.ncols == other.ncols && self.data[..] == other.data[..]
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T: Eq, R: Dim, C: Dim, A: RawAllocator> Eq for VecStorage<T, R, C, A> {}
+
+#[cfg(not(feature = "allocator_api"))]
+impl<T, R: Dim, C: Dim> VecStorage<T, R, C> {
+    /// Creates a new dynamic matrix data storage from the given vector and shape.
+    #[inline]
+    pub fn new(nrows: R, ncols: C, data: Vec<T>) -> Self {
+        assert!(
+            nrows.value() * ncols.value() == data.len(),
+            "Data storage buffer dimension mismatch."
+        );
+        Self { data, nrows, ncols }
+    }
+
+    /// The shape of this storage.
+    #[inline]
+    pub fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    /// Resizes this storage in-place to hold exactly `sz` elements.
+    ///
+    /// Not available under the `no-oom-abort` feature, since growing the buffer goes through
+    /// the infallible, abort-on-OOM `Vec::reserve_exact`. Use [`Self::try_resize`] instead.
+    ///
+    /// # Safety invariant
+    /// If `sz` is greater than the current length, the extra slots are left uninitialized; the
+    /// caller is responsible for writing to them before they are read.
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    pub fn resize(mut self, sz: usize) -> Vec<T> {
+        unsafe {
+            if sz < self.data.len() {
+                self.data.set_len(sz);
+                self.data.shrink_to_fit();
+            } else {
+                self.data.reserve_exact(sz - self.data.len());
+                self.data.set_len(sz);
+            }
+        }
+        self.data
+    }
+
+    /// Resizes this storage in-place to hold exactly `sz` elements, reporting an error instead
+    /// of panicking/aborting if growing the buffer fails.
+    ///
+    /// # Safety invariant
+    /// If `sz` is greater than the current length, the extra slots are left uninitialized; the
+    /// caller is responsible for writing to them before they are read.
+    #[inline]
+    pub fn try_resize(mut self, sz: usize) -> Result<Vec<T>, TryReserveError> {
+        unsafe {
+            if sz < self.data.len() {
+                self.data.set_len(sz);
+                self.data.shrink_to_fit();
+            } else {
+                self.data.try_reserve_exact(sz - self.data.len())?;
+                self.data.set_len(sz);
+            }
+        }
+        Ok(self.data)
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+impl<T, R: Dim, C: Dim, A: RawAllocator + Clone> VecStorage<T, R, C, A> {
+    /// Creates a new dynamic matrix data storage from the given `Vec` (and its allocator) and
+    /// shape.
+    #[inline]
+    pub fn new(nrows: R, ncols: C, data: Vec<T, A>) -> Self {
+        assert!(
+            nrows.value() * ncols.value() == data.len(),
+            "Data storage buffer dimension mismatch."
+        );
+        Self { data, nrows, ncols }
+    }
+
+    /// The shape of this storage.
+    #[inline]
+    pub fn shape(&self) -> (R, C) {
+        (self.nrows, self.ncols)
+    }
+
+    /// Resizes this storage in-place to hold exactly `sz` elements.
+    ///
+    /// The underlying `Vec` is grown or shrunk within its own allocator `A`, so a matrix built
+    /// in a bump/pool allocator never falls back to `Global` across reallocations.
+    ///
+    /// Not available under the `no-oom-abort` feature, since growing the buffer goes through
+    /// the infallible, abort-on-OOM `Vec::reserve_exact`. Use [`Self::try_resize`] instead.
+    ///
+    /// # Safety invariant
+    /// If `sz` is greater than the current length, the extra slots are left uninitialized; the
+    /// caller is responsible for writing to them before they are read.
+    #[inline]
+    #[cfg(not(feature = "no-oom-abort"))]
+    pub fn resize(mut self, sz: usize) -> Vec<T, A> {
+        unsafe {
+            if sz < self.data.len() {
+                self.data.set_len(sz);
+                self.data.shrink_to_fit();
+            } else {
+                self.data.reserve_exact(sz - self.data.len());
+                self.data.set_len(sz);
+            }
+        }
+        self.data
+    }
+
+    /// Resizes this storage in-place to hold exactly `sz` elements, reporting an error instead
+    /// of panicking/aborting if growing the buffer (within `A`) fails.
+    ///
+    /// # Safety invariant
+    /// If `sz` is greater than the current length, the extra slots are left uninitialized; the
+    /// caller is responsible for writing to them before they are read.
+    #[inline]
+    pub fn try_resize(mut self, sz: usize) -> Result<Vec<T, A>, TryReserveError> {
+        unsafe {
+            if sz < self.data.len() {
+                self.data.set_len(sz);
+                self.data.shrink_to_fit();
+            } else {
+                self.data.try_reserve_exact(sz - self.data.len())?;
+                self.data.set_len(sz);
+            }
+        }
+        Ok(self.data)
+    }
+}
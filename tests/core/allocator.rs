@@ -0,0 +1,173 @@
+use na::DMatrix;
+
+#[test]
+fn try_from_iterator_matches_from_iterator() {
+    let m = DMatrix::try_from_iterator(2, 3, 0..6).unwrap();
+    assert_eq!(m, DMatrix::from_iterator(2, 3, 0..6));
+}
+
+#[test]
+fn try_zeros_matches_zeros() {
+    let m = DMatrix::<f64>::try_zeros(4, 4).unwrap();
+    assert_eq!(m, DMatrix::zeros(4, 4));
+}
+
+#[test]
+#[should_panic(expected = "the iterator did not yield the correct number of elements")]
+fn try_from_iterator_too_short_panics() {
+    let _ = DMatrix::try_from_iterator(2, 3, 0..5);
+}
+
+#[test]
+#[should_panic(expected = "the iterator did not yield the correct number of elements")]
+fn try_from_iterator_too_long_panics() {
+    let _ = DMatrix::try_from_iterator(2, 3, 0..7);
+}
+
+// Regression test for a derive that bounded `VecStorage`'s `Eq`/`PartialEq` impls on the
+// allocator `A`: `Global` (the default) doesn't implement those traits, so this must compile
+// and compare correctly with no extra bound on `A`.
+#[cfg(feature = "allocator_api")]
+#[test]
+fn vec_storage_with_global_allocator_round_trips_equality() {
+    use na::base::dimension::Dynamic;
+    use na::base::VecStorage;
+
+    let a: VecStorage<f64, Dynamic, Dynamic> =
+        VecStorage::new(Dynamic::new(2), Dynamic::new(2), vec![1.0, 2.0, 3.0, 4.0]);
+    let b = a.clone();
+    assert_eq!(a, b);
+
+    let c: VecStorage<f64, Dynamic, Dynamic> =
+        VecStorage::new(Dynamic::new(2), Dynamic::new(2), vec![1.0, 2.0, 3.0, 5.0]);
+    assert_ne!(a, c);
+}
+
+/// A minimal bump allocator over a fixed-size buffer, used below to prove that `AllocatorIn`
+/// actually threads its allocations through a caller-chosen allocator instead of silently
+/// falling back to `Global`.
+#[cfg(feature = "allocator_api")]
+mod bump {
+    use std::alloc::{AllocError, Allocator, Layout};
+    use std::cell::Cell;
+    use std::ptr::NonNull;
+    use std::rc::Rc;
+
+    struct Inner {
+        buf: *mut u8,
+        cap: usize,
+        offset: Cell<usize>,
+    }
+
+    impl Inner {
+        fn new(cap: usize) -> Self {
+            let buf = Box::into_raw(vec![0u8; cap].into_boxed_slice()) as *mut u8;
+            Inner {
+                buf,
+                cap,
+                offset: Cell::new(0),
+            }
+        }
+    }
+
+    impl Drop for Inner {
+        fn drop(&mut self) {
+            // Safety: `buf` was obtained from `Box::into_raw` on a `[u8; cap]` above and is
+            // dropped at most once, here.
+            unsafe {
+                drop(Box::from_raw(std::slice::from_raw_parts_mut(
+                    self.buf, self.cap,
+                )));
+            }
+        }
+    }
+
+    std::thread_local! {
+        // `AllocatorIn<Bump>` calls `Bump::default()` each time it builds a new buffer; routing
+        // it through a thread-local handle lets the test observe, from the outside, whether bytes
+        // were actually handed out by *this* allocator.
+        static ARENA: Rc<Inner> = Rc::new(Inner::new(4096));
+    }
+
+    #[derive(Clone)]
+    pub struct Bump(Rc<Inner>);
+
+    impl Default for Bump {
+        fn default() -> Self {
+            Bump(ARENA.with(Rc::clone))
+        }
+    }
+
+    impl Bump {
+        pub fn bytes_allocated(&self) -> usize {
+            self.0.offset.get()
+        }
+    }
+
+    unsafe impl Allocator for Bump {
+        fn allocate(&self, layout: Layout) -> Result<NonNull<[u8]>, AllocError> {
+            let start = self.0.offset.get();
+            let align = layout.align();
+            let aligned = (start + align - 1) & !(align - 1);
+            let end = aligned.checked_add(layout.size()).ok_or(AllocError)?;
+            if end > self.0.cap {
+                return Err(AllocError);
+            }
+            self.0.offset.set(end);
+
+            // Safety: `aligned` and `end` were just checked to fall within `[0, self.0.cap]`.
+            let ptr = unsafe { self.0.buf.add(aligned) };
+            let ptr = NonNull::new(ptr).ok_or(AllocError)?;
+            Ok(NonNull::slice_from_raw_parts(ptr, layout.size()))
+        }
+
+        unsafe fn deallocate(&self, _ptr: NonNull<u8>, _layout: Layout) {
+            // A bump allocator never reclaims individual allocations.
+        }
+    }
+}
+
+#[cfg(feature = "allocator_api")]
+#[test]
+fn allocator_in_builds_through_a_custom_allocator() {
+    use bump::Bump;
+    use na::base::allocator_in::AllocatorIn;
+    use na::base::dimension::Dynamic;
+
+    let before = Bump::default().bytes_allocated();
+
+    let m = AllocatorIn::<Bump>::try_from_iterator_generic(Dynamic::new(2), Dynamic::new(3), 0..6)
+        .unwrap();
+    assert_eq!(m.shape(), (Dynamic::new(2), Dynamic::new(3)));
+
+    // If this had silently gone through `Global` instead of `Bump`, the arena's bump offset
+    // would never have moved.
+    assert!(Bump::default().bytes_allocated() > before);
+}
+
+#[cfg(feature = "no-oom-abort")]
+#[test]
+fn no_oom_abort_try_surface_builds_and_resizes() {
+    use na::base::allocator::Reallocator;
+    use na::base::default_allocator::DefaultAllocator;
+    use na::base::dimension::Dynamic;
+    use na::base::VecStorage;
+
+    let m = DMatrix::try_zeros(2, 3).unwrap();
+    assert_eq!(
+        m,
+        DMatrix::try_from_iterator(2, 3, std::iter::repeat(0.0f64).take(6)).unwrap()
+    );
+
+    let buf: VecStorage<f64, Dynamic, Dynamic> =
+        VecStorage::new(Dynamic::new(2), Dynamic::new(3), vec![0.0; 6]);
+    let resized = unsafe {
+        <DefaultAllocator as Reallocator<f64, Dynamic, Dynamic, Dynamic, Dynamic>>::try_reallocate_copy(
+            Dynamic::new(3),
+            Dynamic::new(3),
+            buf,
+        )
+    }
+    .unwrap();
+    assert_eq!(resized.shape(), (Dynamic::new(3), Dynamic::new(3)));
+}
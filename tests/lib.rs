@@ -1,3 +1,5 @@
+#![cfg_attr(feature = "allocator_api", feature(allocator_api))]
+
 #[cfg(not(all(feature = "debug", feature = "compare", feature = "rand",)))]
 compile_error!(
     "Please enable the `debug`, `compare`, `rand` features in order to compile and run the tests.